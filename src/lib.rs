@@ -2,20 +2,351 @@ use bevy_app::prelude::*;
 use bevy_ecs::prelude::*;
 pub use bevy_ecs::world::{CommandQueue, World};
 use bevy_tasks::{Task, futures_lite::future, prelude::*};
+use std::any::Any;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+/// A boxed closure run against the live [`World`] on behalf of an [`AsyncWorld`]
+/// handle. The type-erased return value is shipped back over the response channel.
+#[doc(hidden)]
+pub type WorldClosure = Box<dyn FnOnce(&mut World) -> Box<dyn Any + Send> + Send>;
+/// A single mid-flight world access: the closure to run plus the oneshot sender
+/// that carries its result back to the awaiting task.
+#[doc(hidden)]
+pub type WorldRequest = (WorldClosure, async_channel::Sender<Box<dyn Any + Send>>);
+
+/// A handle given to a [`task!`] async block that lets it read or write the live
+/// [`World`] multiple times mid-flight, rather than only once through the final
+/// [`CommandQueue`].
+///
+/// Each [`AsyncWorld::run`] call ships a closure over an unbounded channel to the
+/// matching receiver on the task's [`TaskComponent`]; `poll_tasks` drains those
+/// requests against `&mut World` and answers them before the task is polled again,
+/// so a request/response round-trip completes within one frame.
+#[derive(Clone)]
+pub struct AsyncWorld {
+    sender: async_channel::Sender<WorldRequest>,
+}
+
+impl AsyncWorld {
+    /// Creates a paired handle and receiver. The handle travels into the spawned
+    /// future; the receiver is stored on the [`TaskComponent`] and drained by
+    /// `poll_tasks`.
+    #[doc(hidden)]
+    pub fn channel() -> (Self, async_channel::Receiver<WorldRequest>) {
+        let (sender, receiver) = async_channel::unbounded();
+        (Self { sender }, receiver)
+    }
+
+    /// Runs `f` against the live [`World`] and awaits its result. The call resolves
+    /// within the same frame it is issued, letting a task read a resource, compute,
+    /// and read another in a single pass.
+    pub async fn run<R, F>(&self, f: F) -> R
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut World) -> R + Send + 'static,
+    {
+        let (tx, rx) = async_channel::bounded(1);
+        let closure: WorldClosure = Box::new(move |world| Box::new(f(world)) as Box<dyn Any + Send>);
+        self.sender
+            .send((closure, tx))
+            .await
+            .expect("AsyncWorld request channel closed");
+        let result = rx
+            .recv()
+            .await
+            .expect("AsyncWorld response channel closed");
+        *result
+            .downcast::<R>()
+            .expect("AsyncWorld result type mismatch")
+    }
+}
+
+/// Lifecycle state of a spawned task, queryable through a [`TaskHandle`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TaskState {
+    /// The task is still running (or awaiting a mid-flight world access).
+    InProgress,
+    /// The task finished and applied its final [`CommandQueue`].
+    Completed,
+    /// The task was cancelled before completing; its entity is cleaned up without
+    /// firing the completion event.
+    Cancelled,
+}
+
+/// A handle to a spawned task, returned by a [`task!`] invocation. Wraps the backing
+/// [`Entity`] so callers can cancel the task or query its [`TaskState`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TaskHandle {
+    pub entity: Entity,
+}
+
+/// How long to wait between retry attempts.
+#[derive(Clone, Copy, Debug)]
+pub enum Backoff {
+    /// A constant delay between every attempt.
+    Fixed(Duration),
+    /// `min(base * factor^(attempt - 1), max)`.
+    Exponential {
+        base: Duration,
+        factor: f64,
+        max: Duration,
+    },
+}
+
+impl Backoff {
+    /// The delay to wait before the given 1-based retry `attempt`.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        match *self {
+            Backoff::Fixed(delay) => delay,
+            Backoff::Exponential { base, factor, max } => {
+                let scaled = base.as_secs_f64() * factor.powi(attempt.saturating_sub(1) as i32);
+                Duration::from_secs_f64(scaled.min(max.as_secs_f64()))
+            }
+        }
+    }
+}
+
+/// Retry configuration for a fallible [`task!`]. Transient `Err`s are retried up to
+/// `max_attempts` times, waiting `backoff` between attempts; the final failure triggers
+/// a [`TaskFailed`] event carrying the error.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub backoff: Backoff,
+}
+
+/// Event triggered when a fallible task exhausts its [`RetryPolicy`], carrying the last
+/// error so observers can react.
+#[derive(Event)]
+pub struct TaskFailed<E: Send + Sync + 'static>(pub E);
+
+/// Sleeps for `duration` on the async reactor without blocking a pool thread.
+pub async fn sleep(duration: Duration) {
+    async_io::Timer::after(duration).await;
+}
+
+/// A handle given to a [`task_progress!`] async block so it can stream incremental
+/// progress back into the [`World`] while it runs. Each [`ProgressSender::send`] pushes
+/// onto an unbounded channel whose receiver lives on the [`TaskComponent`]; `poll_tasks`
+/// drains it each frame and triggers a [`TaskProgress`] event, flushing everything still
+/// buffered before the task's completion event fires.
+#[derive(Clone)]
+pub struct ProgressSender<P> {
+    sender: async_channel::Sender<P>,
+}
+
+impl<P: Send + 'static> ProgressSender<P> {
+    /// Creates a paired sender and receiver. The sender travels into the spawned future;
+    /// the receiver is wrapped in the drain stored on the [`TaskComponent`].
+    #[doc(hidden)]
+    pub fn channel() -> (Self, async_channel::Receiver<P>) {
+        let (sender, receiver) = async_channel::unbounded();
+        (Self { sender }, receiver)
+    }
+
+    /// Emits one progress value. It lands in the `World` as a [`TaskProgress<P>`] event
+    /// on the next `poll_tasks` pass. Dropped silently once the task's entity is gone.
+    pub fn send(&self, progress: P) {
+        let _ = self.sender.try_send(progress);
+    }
+}
+
+/// Event triggered once per progress value streamed by a [`task_progress!`] task, so UI
+/// systems can observe incremental updates (e.g. drive a progress bar).
+#[derive(Event)]
+pub struct TaskProgress<P: Send + Sync + 'static>(pub P);
+
+/// Drains a task's buffered progress into the live [`World`], triggering a
+/// [`TaskProgress`] for each value. Stored type-erased on the [`TaskComponent`] so
+/// `poll_tasks` stays non-generic.
+#[doc(hidden)]
+pub type ProgressDrain = Box<dyn FnMut(&mut World) + Send + Sync>;
+
+/// Recurring schedule for a [`task_every!`] task: how often to run and when the next
+/// run is due.
+pub struct Interval {
+    pub period: Duration,
+    pub next_fire: Instant,
+}
+
+/// Spawns one run of a recurring task's inner future.
+#[doc(hidden)]
+pub type RespawnFn = Box<dyn FnMut() -> Task<CommandQueue> + Send + Sync>;
 
 #[derive(Component)]
-pub struct TaskComponent(pub Task<CommandQueue>);
+pub struct TaskComponent {
+    pub task: Option<Task<CommandQueue>>,
+    pub requests: async_channel::Receiver<WorldRequest>,
+    pub state: TaskState,
+    /// Number of times the task body has been run (1 for non-retrying tasks).
+    pub attempts: Arc<AtomicU32>,
+    /// Recurring schedule, or `None` for a one-shot task.
+    pub interval: Option<Interval>,
+    /// Re-spawns the inner future for a recurring task; `None` for a one-shot task.
+    pub respawn: Option<RespawnFn>,
+    /// Drains streamed progress into the `World`; `None` unless spawned with
+    /// [`task_progress!`].
+    pub progress_drain: Option<ProgressDrain>,
+}
+
+/// Extends [`World`] with helpers to cancel tasks and query their state.
+pub trait TaskWorldExt {
+    /// Aborts the task on the pool and despawns its entity without firing the
+    /// completion event.
+    fn cancel_task(&mut self, handle: TaskHandle);
+    /// Returns the current [`TaskState`], or `None` once the entity no longer exists.
+    fn task_state(&self, handle: TaskHandle) -> Option<TaskState>;
+    /// Returns how many times the task body has run, or `None` once the entity no
+    /// longer exists.
+    fn task_attempts(&self, handle: TaskHandle) -> Option<u32>;
+}
+
+impl TaskWorldExt for World {
+    fn cancel_task(&mut self, handle: TaskHandle) {
+        if let Some(mut comp) = self.get_mut::<TaskComponent>(handle.entity) {
+            comp.state = TaskState::Cancelled;
+            // Dropping the `Task` aborts it on the bevy_tasks pool; the entity stays
+            // alive so the `Cancelled` state is queryable until `poll_tasks` reaps it
+            // on the next pass, matching the two-phase cleanup of `Completed` tasks.
+            comp.task = None;
+            comp.respawn = None;
+        }
+    }
+
+    fn task_state(&self, handle: TaskHandle) -> Option<TaskState> {
+        self.get::<TaskComponent>(handle.entity).map(|comp| comp.state)
+    }
+
+    fn task_attempts(&self, handle: TaskHandle) -> Option<u32> {
+        self.get::<TaskComponent>(handle.entity)
+            .map(|comp| comp.attempts.load(Ordering::Relaxed))
+    }
+}
+
+/// Extends [`Commands`] with a deferred [`cancel_task`](TaskWorldExt::cancel_task).
+pub trait TaskCommandsExt {
+    fn cancel_task(&mut self, handle: TaskHandle);
+}
+
+impl TaskCommandsExt for Commands<'_, '_> {
+    fn cancel_task(&mut self, handle: TaskHandle) {
+        self.queue(move |world: &mut World| world.cancel_task(handle));
+    }
+}
 
-fn poll_tasks(mut commands: Commands, tasks: Query<&mut TaskComponent>) {
-    for mut task in tasks {
-        if let Some(mut q) = block_on(future::poll_once(&mut task.0)) {
-            commands.append(&mut q);
+/// Runs the entity's progress drain (if any) against `&mut World`, temporarily moving it
+/// off the component so the closure can borrow the world exclusively.
+fn drain_progress(world: &mut World, entity: Entity) {
+    let mut drain = world
+        .get_mut::<TaskComponent>(entity)
+        .and_then(|mut comp| comp.progress_drain.take());
+    if let Some(drain) = drain.as_mut() {
+        drain(world);
+    }
+    if let Some(drain) = drain
+        && let Some(mut comp) = world.get_mut::<TaskComponent>(entity)
+    {
+        comp.progress_drain = Some(drain);
+    }
+}
+
+fn poll_tasks(world: &mut World) {
+    let mut query = world.query_filtered::<Entity, With<TaskComponent>>();
+    let entities: Vec<Entity> = query.iter(world).collect();
+    for entity in entities {
+        // A completion event applied for an earlier entity may have run an observer
+        // that despawned this one, so the snapshot can be stale — skip vanished entities.
+        let Some(comp) = world.get::<TaskComponent>(entity) else {
+            continue;
+        };
+        // Reap entities left behind by a previous pass: cancelled tasks (never applying a
+        // partial `CommandQueue`) and finished tasks, whose `Completed` state stays
+        // queryable for the frame it is set before being cleaned up here.
+        if matches!(comp.state, TaskState::Completed | TaskState::Cancelled) {
+            world.despawn(entity);
+            continue;
+        }
+        let requests = comp.requests.clone();
+        // Flush any progress streamed since the last frame before touching the task.
+        drain_progress(world, entity);
+        // Alternate between servicing mid-flight world requests and polling the
+        // task, so a request issued during one poll is answered and the task
+        // resumed within this same frame (FIFO order, every `Update`).
+        loop {
+            let mut serviced = false;
+            while let Ok((closure, tx)) = requests.try_recv() {
+                let result = closure(world);
+                let _ = tx.try_send(result);
+                serviced = true;
+            }
+            let done = {
+                let mut comp = world.get_mut::<TaskComponent>(entity).unwrap();
+                match comp.task.as_mut() {
+                    Some(task) => block_on(future::poll_once(task)),
+                    None => None,
+                }
+            };
+            if let Some(mut q) = done {
+                let recurring = world
+                    .get::<TaskComponent>(entity)
+                    .is_some_and(|comp| comp.interval.is_some());
+                if let Some(mut comp) = world.get_mut::<TaskComponent>(entity) {
+                    if recurring {
+                        // A recurring run finished: go idle and arm the next fire,
+                        // but keep the entity alive for the next period.
+                        comp.task = None;
+                        if let Some(interval) = comp.interval.as_mut() {
+                            interval.next_fire = Instant::now() + interval.period;
+                        }
+                    } else {
+                        comp.state = TaskState::Completed;
+                    }
+                }
+                // Flush all buffered progress before the completion event fires.
+                drain_progress(world, entity);
+                q.apply(world);
+                break;
+            }
+            if !serviced && requests.is_empty() {
+                break;
+            }
+        }
+
+        // Recurring: re-spawn the inner future once the previous run has been
+        // appended and the period has elapsed.
+        let due = world.get::<TaskComponent>(entity).is_some_and(|comp| {
+            comp.task.is_none()
+                && comp
+                    .interval
+                    .as_ref()
+                    .is_some_and(|interval| Instant::now() >= interval.next_fire)
+        });
+        if due {
+            let mut comp = world.get_mut::<TaskComponent>(entity).unwrap();
+            if let Some(respawn) = comp.respawn.as_mut() {
+                let task = respawn();
+                comp.task = Some(task);
+            }
         }
     }
 }
 
 /// Creates an asychronously executing task. When finished, may optionally send an event.
 ///
+/// The async block receives the final [`CommandQueue`] and an [`AsyncWorld`] handle.
+/// Use the queue to schedule a single mutation that runs when the task completes, and
+/// the handle to read or write the live [`World`] mid-flight.
+///
+/// Three forms are supported:
+/// - `task!(pool, block)` — no completion event.
+/// - `task!(pool, event, block)` — the eagerly-constructed `event` is triggered on
+///   completion.
+/// - `task!(pool, block, |out: T| event)` — the block *returns* a value of type
+///   `T: Send + 'static` which is passed to the builder closure to construct the
+///   triggered event, letting observers read what the task produced.
+///
 /// Example usage:
 /// ```rust
 /// # use bevy_app::prelude::*;
@@ -37,8 +368,9 @@ fn poll_tasks(mut commands: Commands, tasks: Query<&mut TaskComponent>) {
 /// let task = task!(
 ///     IoTaskPool,
 ///     MyEvent::default(), // (optional)
-///     async move |q: &mut CommandQueue| {
-///         // do some async stuff
+///     async move |q: &mut CommandQueue, world: AsyncWorld| {
+///         // read the live world mid-flight
+///         let _ = world.run(|_w: &mut World| 42).await;
 ///         q.push(|world: &mut World| {
 ///             // do some world mutation
 ///         });
@@ -50,25 +382,264 @@ macro_rules! task {
     ($pool_type:path, $block:expr) => {
         task!(@inner $pool_type, $block)
     };
+    // Typed-result form: the block returns `T` and `builder` turns it into the event.
+    // Distinguished from the eager-event form by the leading `|` of the builder closure.
+    ($pool_type:path, $block:expr, |$($builder:tt)+) => {
+        task!(@typed $pool_type, $block, (|$($builder)+))
+    };
+    // Retry form: the block returns `Result<T, E>` and is retried per the policy.
+    // Distinguished from the eager-event form by the leading `RetryPolicy` literal.
+    ($pool_type:path, RetryPolicy { $($policy:tt)* }, $block:expr) => {
+        task!(@retry $pool_type, RetryPolicy { $($policy)* }, $block)
+    };
     ($pool_type:path, $event:expr, $block:expr) => {
         task!(@inner $pool_type, $block, $event)
     };
     (@inner $pool_type:path, $block:expr $(, $event:expr)?)  => {
-        (move |world: &mut $crate::World| {
+        (move |world: &mut $crate::World| -> $crate::TaskHandle {
             let mut entity = world.spawn_empty();
             let id = entity.id();
+            let (async_world, requests) = $crate::AsyncWorld::channel();
             let task = <$pool_type>::get().spawn(async move {
                 let mut q = $crate::CommandQueue::default();
-                ($block)(&mut q).await;
+                ($block)(&mut q, async_world).await;
+                q.push(move |_world: &mut $crate::World| {
+                    $(_world.trigger($event))?
+                });
+                q
+            });
+            entity.insert($crate::TaskComponent {
+                task: Some(task),
+                requests,
+                state: $crate::TaskState::InProgress,
+                attempts: ::std::sync::Arc::new(::std::sync::atomic::AtomicU32::new(1)),
+                interval: None,
+                respawn: None,
+                progress_drain: None,
+            });
+            $crate::TaskHandle { entity: id }
+        })
+    };
+    (@typed $pool_type:path, $block:expr, $builder:expr) => {
+        (move |world: &mut $crate::World| -> $crate::TaskHandle {
+            let mut entity = world.spawn_empty();
+            let id = entity.id();
+            let (async_world, requests) = $crate::AsyncWorld::channel();
+            let builder = $builder;
+            let task = <$pool_type>::get().spawn(async move {
+                let mut q = $crate::CommandQueue::default();
+                let out = ($block)(&mut q, async_world).await;
                 q.push(move |world: &mut $crate::World| {
-                    world.despawn(id);
-                    $(world.trigger($event))?
+                    world.trigger(builder(out));
                 });
                 q
             });
-            entity.insert($crate::TaskComponent(task));
+            entity.insert($crate::TaskComponent {
+                task: Some(task),
+                requests,
+                state: $crate::TaskState::InProgress,
+                attempts: ::std::sync::Arc::new(::std::sync::atomic::AtomicU32::new(1)),
+                interval: None,
+                respawn: None,
+                progress_drain: None,
+            });
+            $crate::TaskHandle { entity: id }
         })
-    }
+    };
+    (@retry $pool_type:path, $policy:expr, $block:expr) => {
+        (move |world: &mut $crate::World| -> $crate::TaskHandle {
+            let mut entity = world.spawn_empty();
+            let id = entity.id();
+            let (async_world, requests) = $crate::AsyncWorld::channel();
+            let policy: $crate::RetryPolicy = $policy;
+            let user = $block;
+            let attempts = ::std::sync::Arc::new(::std::sync::atomic::AtomicU32::new(0));
+            let task_attempts = attempts.clone();
+            let task = <$pool_type>::get().spawn(async move {
+                let mut attempt: u32 = 0;
+                // A fresh queue per attempt so a failed attempt's world mutations are
+                // discarded; only the successful attempt's queue is kept.
+                let outcome = loop {
+                    attempt += 1;
+                    task_attempts.store(attempt, ::std::sync::atomic::Ordering::Relaxed);
+                    let mut q = $crate::CommandQueue::default();
+                    match (user)(&mut q, async_world.clone()).await {
+                        Ok(_) => break Ok(q),
+                        Err(err) => {
+                            if attempt >= policy.max_attempts {
+                                break Err(err);
+                            }
+                            $crate::sleep(policy.backoff.delay(attempt)).await;
+                        }
+                    }
+                };
+                match outcome {
+                    Ok(q) => q,
+                    Err(err) => {
+                        let mut q = $crate::CommandQueue::default();
+                        q.push(move |world: &mut $crate::World| {
+                            world.trigger($crate::TaskFailed(err));
+                        });
+                        q
+                    }
+                }
+            });
+            entity.insert($crate::TaskComponent {
+                task: Some(task),
+                requests,
+                state: $crate::TaskState::InProgress,
+                attempts,
+                interval: None,
+                respawn: None,
+                progress_drain: None,
+            });
+            $crate::TaskHandle { entity: id }
+        })
+    };
+}
+
+/// Spawns a task that first waits `Duration` and then runs like [`task!`], optionally
+/// triggering an event on completion.
+///
+/// `task_after!(pool, duration, block)` or `task_after!(pool, duration, event, block)`.
+#[macro_export]
+macro_rules! task_after {
+    ($pool_type:path, $delay:expr, $block:expr) => {
+        task_after!(@inner $pool_type, $delay, $block)
+    };
+    ($pool_type:path, $delay:expr, $event:expr, $block:expr) => {
+        task_after!(@inner $pool_type, $delay, $block, $event)
+    };
+    (@inner $pool_type:path, $delay:expr, $block:expr $(, $event:expr)?) => {
+        (move |world: &mut $crate::World| -> $crate::TaskHandle {
+            let mut entity = world.spawn_empty();
+            let id = entity.id();
+            let (async_world, requests) = $crate::AsyncWorld::channel();
+            let delay: ::std::time::Duration = $delay;
+            let task = <$pool_type>::get().spawn(async move {
+                $crate::sleep(delay).await;
+                let mut q = $crate::CommandQueue::default();
+                ($block)(&mut q, async_world).await;
+                q.push(move |_world: &mut $crate::World| {
+                    $(_world.trigger($event))?
+                });
+                q
+            });
+            entity.insert($crate::TaskComponent {
+                task: Some(task),
+                requests,
+                state: $crate::TaskState::InProgress,
+                attempts: ::std::sync::Arc::new(::std::sync::atomic::AtomicU32::new(1)),
+                interval: None,
+                respawn: None,
+                progress_drain: None,
+            });
+            $crate::TaskHandle { entity: id }
+        })
+    };
+}
+
+/// Spawns a recurring task that runs immediately and then re-runs every `Duration`,
+/// optionally triggering an event once per run.
+///
+/// `task_every!(pool, duration, block)` or `task_every!(pool, duration, event, block)`.
+/// The block is re-run each period, so it must be cloneable (capture only cloneable
+/// data). The task keeps its entity alive between runs and is cancellable through the
+/// [`TaskHandle`] API.
+#[macro_export]
+macro_rules! task_every {
+    ($pool_type:path, $period:expr, $block:expr) => {
+        task_every!(@inner $pool_type, $period, $block)
+    };
+    ($pool_type:path, $period:expr, $event:expr, $block:expr) => {
+        task_every!(@inner $pool_type, $period, $block, $event)
+    };
+    (@inner $pool_type:path, $period:expr, $block:expr $(, $event:expr)?) => {
+        (move |world: &mut $crate::World| -> $crate::TaskHandle {
+            let mut entity = world.spawn_empty();
+            let id = entity.id();
+            let (async_world, requests) = $crate::AsyncWorld::channel();
+            let period: ::std::time::Duration = $period;
+            let user = $block;
+            let spawn_world = async_world.clone();
+            let respawn = move || {
+                let user = user.clone();
+                let async_world = spawn_world.clone();
+                <$pool_type>::get().spawn(async move {
+                    let mut q = $crate::CommandQueue::default();
+                    (user)(&mut q, async_world).await;
+                    q.push(move |_world: &mut $crate::World| {
+                        // Recurring tasks keep their entity; never despawn on completion.
+                        $(_world.trigger($event))?
+                    });
+                    q
+                })
+            };
+            let task = respawn();
+            entity.insert($crate::TaskComponent {
+                task: Some(task),
+                requests,
+                state: $crate::TaskState::InProgress,
+                attempts: ::std::sync::Arc::new(::std::sync::atomic::AtomicU32::new(1)),
+                interval: Some($crate::Interval {
+                    period,
+                    next_fire: ::std::time::Instant::now() + period,
+                }),
+                respawn: Some(::std::boxed::Box::new(respawn)),
+                progress_drain: None,
+            });
+            $crate::TaskHandle { entity: id }
+        })
+    };
+}
+
+/// Spawns a task that can stream incremental progress back into the `World` while it
+/// runs. The async block receives a third argument, a [`ProgressSender<P>`]; each
+/// `progress.send(p)` triggers a [`TaskProgress<P>`] event on the next `poll_tasks`
+/// pass, with all buffered progress flushed before the optional completion event fires.
+///
+/// The progress type `P` must be named so the typed channel and event can be built:
+/// `task_progress!(pool, P, block)` or `task_progress!(pool, P, event, block)`.
+#[macro_export]
+macro_rules! task_progress {
+    ($pool_type:path, $p:ty, $block:expr) => {
+        task_progress!(@inner $pool_type, $p, $block)
+    };
+    ($pool_type:path, $p:ty, $event:expr, $block:expr) => {
+        task_progress!(@inner $pool_type, $p, $block, $event)
+    };
+    (@inner $pool_type:path, $p:ty, $block:expr $(, $event:expr)?) => {
+        (move |world: &mut $crate::World| -> $crate::TaskHandle {
+            let mut entity = world.spawn_empty();
+            let id = entity.id();
+            let (async_world, requests) = $crate::AsyncWorld::channel();
+            let (progress, progress_rx) = $crate::ProgressSender::<$p>::channel();
+            let task = <$pool_type>::get().spawn(async move {
+                let mut q = $crate::CommandQueue::default();
+                ($block)(&mut q, async_world, progress).await;
+                q.push(move |_world: &mut $crate::World| {
+                    $(_world.trigger($event))?
+                });
+                q
+            });
+            let progress_drain: $crate::ProgressDrain =
+                ::std::boxed::Box::new(move |world: &mut $crate::World| {
+                    while let Ok(p) = progress_rx.try_recv() {
+                        world.trigger($crate::TaskProgress(p));
+                    }
+                });
+            entity.insert($crate::TaskComponent {
+                task: Some(task),
+                requests,
+                state: $crate::TaskState::InProgress,
+                attempts: ::std::sync::Arc::new(::std::sync::atomic::AtomicU32::new(1)),
+                interval: None,
+                respawn: None,
+                progress_drain: Some(progress_drain),
+            });
+            $crate::TaskHandle { entity: id }
+        })
+    };
 }
 
 pub struct TaskPlugin;
@@ -124,7 +695,7 @@ mod test {
             task!(
                 ComputeTaskPool,
                 Ran::<IoTaskPool>::default(),
-                async move |q: &mut CommandQueue| {
+                async move |q: &mut CommandQueue, _world: AsyncWorld| {
                     debug!("In IoTaskPool");
                     q.push(|world: &mut World| {
                         world.resource_mut::<TestResults>().io_task_pool = true;
@@ -134,7 +705,7 @@ mod test {
             task!(
                 ComputeTaskPool,
                 Ran::<ComputeTaskPool>::default(),
-                async move |q: &mut CommandQueue| {
+                async move |q: &mut CommandQueue, _world: AsyncWorld| {
                     debug!("In ComputeTaskPool");
                     q.push(|world: &mut World| {
                         world.resource_mut::<TestResults>().compute_task_pool = true;
@@ -144,7 +715,7 @@ mod test {
             task!(
                 AsyncComputeTaskPool,
                 Ran::<AsyncComputeTaskPool>::default(),
-                async move |q: &mut CommandQueue| {
+                async move |q: &mut CommandQueue, _world: AsyncWorld| {
                     // busy-wait 1 sec to test it works
                     debug!("In AsyncComputeTaskPool");
                     let start = Instant::now();
@@ -171,7 +742,12 @@ mod test {
                 res.async_task_pool_observer = true;
             },
         );
-        app.update();
+        // Run frames until the quick io/compute tasks have reported. The async compute
+        // task busy-waits ~1s, so it is still running while these resolve.
+        pump_until(&mut app, |app| {
+            let res = app.world().resource::<TestResults>();
+            res.io_task_pool && res.compute_task_pool
+        });
         let res = app
             .world_mut()
             .get_resource::<TestResults>()
@@ -187,13 +763,14 @@ mod test {
                 async_task_pool_observer: false,
             }
         );
-        // Observers and the entity for polling the async compute task.
+        // The finished quick tasks are reaped a frame after completing, leaving the
+        // three observers and the still-running async compute entity.
+        pump_until(&mut app, |app| app.world_mut().entities().used_count() == 4);
         assert_eq!(app.world_mut().entities().used_count(), 4);
-        // busy wait...
-        let start = Instant::now();
-        while Instant::now().duration_since(start) <= Duration::from_secs(2) {}
-        // ... update again
-        app.update();
+        // Wait out the async busy-wait; its event fires once it finishes.
+        pump_until(&mut app, |app| {
+            app.world().resource::<TestResults>().async_task_pool
+        });
         let res = app
             .world_mut()
             .get_resource::<TestResults>()
@@ -209,7 +786,296 @@ mod test {
                 async_task_pool_observer: true,
             }
         );
-        // Should only contain the observers.
+        // The now-`Completed` async entity is reaped, leaving only the observers.
+        pump_until(&mut app, |app| app.world_mut().entities().used_count() == 3);
         assert_eq!(app.world_mut().entities().used_count(), 3);
     }
+
+    #[derive(Resource, Default)]
+    struct Counter(u32);
+
+    #[test]
+    fn test_async_world_mid_task() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            TaskPlugin,
+        ))
+        .insert_resource(Counter(1))
+        .add_systems(Startup, move |world: &mut World| {
+            task!(
+                ComputeTaskPool,
+                async move |_q: &mut CommandQueue, world: AsyncWorld| {
+                    // read, compute, write back mid-flight
+                    let start = world.run(|w: &mut World| w.resource::<Counter>().0).await;
+                    let doubled = start * 2;
+                    world
+                        .run(move |w: &mut World| w.resource_mut::<Counter>().0 = doubled)
+                        .await;
+                }
+            )(world);
+        });
+        // Several frames for the mid-flight request/response round-trips to resolve on
+        // the worker thread.
+        pump(&mut app, 20);
+        assert_eq!(app.world().resource::<Counter>().0, 2);
+    }
+
+    #[derive(Event)]
+    struct Produced(u32);
+
+    #[test]
+    fn test_typed_result_event() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            TaskPlugin,
+        ))
+        .insert_resource(Counter(0))
+        .add_observer(|trigger: Trigger<Produced>, mut res: ResMut<Counter>| {
+            res.0 = trigger.event().0;
+        })
+        .add_systems(Startup, move |world: &mut World| {
+            task!(
+                ComputeTaskPool,
+                async move |_q: &mut CommandQueue, _world: AsyncWorld| { 7u32 },
+                |out: u32| Produced(out)
+            )(world);
+        });
+        pump(&mut app, 10);
+        assert_eq!(app.world().resource::<Counter>().0, 7);
+    }
+
+    #[derive(Resource)]
+    struct Handle(TaskHandle);
+
+    #[test]
+    fn test_cancel_task() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            TaskPlugin,
+        ))
+        .insert_resource(Counter(0))
+        .add_observer(|_: Trigger<Ran<IoTaskPool>>, mut res: ResMut<Counter>| {
+            res.0 += 1;
+        })
+        .add_systems(Startup, move |world: &mut World| {
+            let handle = task!(
+                AsyncComputeTaskPool,
+                Ran::<IoTaskPool>::default(),
+                async move |_q: &mut CommandQueue, _world: AsyncWorld| {
+                    // Sleep rather than busy-wait so the future has an await point:
+                    // dropping the `Task` on cancel aborts it here and frees the pool
+                    // thread immediately, instead of spinning for the full duration.
+                    sleep(Duration::from_secs(10)).await;
+                }
+            )(world);
+            world.insert_resource(Handle(handle));
+        });
+        app.update();
+        let handle = app.world().resource::<Handle>().0;
+        assert_eq!(app.world().task_state(handle), Some(TaskState::InProgress));
+        app.world_mut().cancel_task(handle);
+        app.update();
+        // Entity is gone and the completion event never fired.
+        assert_eq!(app.world().task_state(handle), None);
+        assert_eq!(app.world().resource::<Counter>().0, 0);
+    }
+
+    #[test]
+    fn test_completed_state_observable() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            TaskPlugin,
+        ))
+        .add_systems(Startup, move |world: &mut World| {
+            let handle = task!(
+                ComputeTaskPool,
+                async move |_q: &mut CommandQueue, _world: AsyncWorld| {}
+            )(world);
+            world.insert_resource(Handle(handle));
+        });
+        let handle = {
+            app.update();
+            app.world().resource::<Handle>().0
+        };
+        // The finished task reports `Completed` for the frame it finishes on, before it
+        // is reaped on the following pass.
+        let mut saw_completed = false;
+        for _ in 0..20 {
+            if app.world().task_state(handle) == Some(TaskState::Completed) {
+                saw_completed = true;
+                break;
+            }
+            app.update();
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        assert!(saw_completed, "task never became observably Completed");
+        // The next pass reaps the entity.
+        app.update();
+        assert_eq!(app.world().task_state(handle), None);
+    }
+
+    #[derive(Resource, Default)]
+    struct Failed(bool);
+
+    #[test]
+    fn test_retry_failure_event() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            TaskPlugin,
+        ))
+        .init_resource::<Failed>()
+        .add_observer(
+            |_: Trigger<TaskFailed<&'static str>>, mut res: ResMut<Failed>| {
+                res.0 = true;
+            },
+        )
+        .add_systems(Startup, move |world: &mut World| {
+            task!(
+                ComputeTaskPool,
+                RetryPolicy {
+                    max_attempts: 1,
+                    backoff: Backoff::Fixed(Duration::from_millis(1))
+                },
+                move |_q: &mut CommandQueue, _world: AsyncWorld| async move {
+                    Err::<(), &'static str>("boom")
+                }
+            )(world);
+        });
+        pump(&mut app, 10);
+        assert!(app.world().resource::<Failed>().0);
+    }
+
+    fn pump(app: &mut App, frames: usize) {
+        for _ in 0..frames {
+            app.update();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    /// Pump frames until `cond` holds, sleeping briefly between them. Returns as soon
+    /// as the condition is met; the frame cap only bounds a genuinely stuck task so a
+    /// failing test reports an assertion rather than hanging. Prefer this over a fixed
+    /// `pump` count whenever a test waits on a worker-thread result, since the shared
+    /// task pools make exact frame counts flaky when tests run in parallel.
+    fn pump_until(app: &mut App, mut cond: impl FnMut(&mut App) -> bool) {
+        for _ in 0..200 {
+            if cond(app) {
+                return;
+            }
+            app.update();
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    }
+
+    #[test]
+    fn test_task_after() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            TaskPlugin,
+        ))
+        .insert_resource(Counter(0))
+        .add_systems(Startup, move |world: &mut World| {
+            task_after!(
+                AsyncComputeTaskPool,
+                Duration::from_millis(40),
+                async move |q: &mut CommandQueue, _world: AsyncWorld| {
+                    q.push(|world: &mut World| world.resource_mut::<Counter>().0 += 1);
+                }
+            )(world);
+        });
+        // Nothing should have run yet on the very first frame.
+        app.update();
+        assert_eq!(app.world().resource::<Counter>().0, 0);
+        pump_until(&mut app, |app| app.world().resource::<Counter>().0 == 1);
+        assert_eq!(app.world().resource::<Counter>().0, 1);
+    }
+
+    #[test]
+    fn test_task_every() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            TaskPlugin,
+        ))
+        .insert_resource(Counter(0))
+        .add_systems(Startup, move |world: &mut World| {
+            let handle = task_every!(
+                AsyncComputeTaskPool,
+                Duration::from_millis(30),
+                async move |q: &mut CommandQueue, _world: AsyncWorld| {
+                    q.push(|world: &mut World| world.resource_mut::<Counter>().0 += 1);
+                }
+            )(world);
+            world.insert_resource(Handle(handle));
+        });
+        pump(&mut app, 1);
+        let handle = app.world().resource::<Handle>().0;
+        // Ran several times and the entity is still alive between runs.
+        pump_until(&mut app, |app| app.world().resource::<Counter>().0 >= 2);
+        let runs = app.world().resource::<Counter>().0;
+        assert!(runs >= 2, "expected multiple runs, got {runs}");
+        assert_eq!(app.world().task_state(handle), Some(TaskState::InProgress));
+        // Cancelling stops further runs.
+        app.world_mut().cancel_task(handle);
+        let after_cancel = app.world().resource::<Counter>().0;
+        // The cancelled entity is reaped a frame later and the counter stops advancing.
+        pump_until(&mut app, |app| app.world().task_state(handle).is_none());
+        assert_eq!(app.world().resource::<Counter>().0, after_cancel);
+        assert_eq!(app.world().task_state(handle), None);
+    }
+
+    #[derive(Resource, Default)]
+    struct Progress {
+        sum: u32,
+        done: bool,
+    }
+
+    #[test]
+    fn test_task_progress() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            ScheduleRunnerPlugin::default(),
+            TaskPlugin,
+        ))
+        .init_resource::<Progress>()
+        .add_observer(|trigger: Trigger<TaskProgress<u32>>, mut res: ResMut<Progress>| {
+            res.sum += trigger.event().0;
+        })
+        .add_observer(|_: Trigger<Ran<IoTaskPool>>, mut res: ResMut<Progress>| {
+            res.done = true;
+        })
+        .add_systems(Startup, move |world: &mut World| {
+            task_progress!(
+                AsyncComputeTaskPool,
+                u32,
+                Ran::<IoTaskPool>::default(),
+                move |_q: &mut CommandQueue,
+                      _world: AsyncWorld,
+                      progress: ProgressSender<u32>| async move {
+                    for step in 1..=3u32 {
+                        progress.send(step);
+                    }
+                }
+            )(world);
+        });
+        pump_until(&mut app, |app| app.world().resource::<Progress>().done);
+        let res = app.world().resource::<Progress>();
+        // Every streamed value was flushed, and the completion event still fired.
+        assert_eq!(res.sum, 6);
+        assert!(res.done);
+    }
 }